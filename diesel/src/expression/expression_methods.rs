@@ -0,0 +1,87 @@
+use expression::AsExpression;
+use expression::predicates::*;
+use types::VarChar;
+
+/// Methods present on all expressions, for construction of boolean
+/// predicates such as the ones found in `users.filter(id.eq(1))`.
+///
+/// This trait is exposed as `diesel::expression_methods::*`.
+pub trait ExpressionMethods: ::expression::Expression + Sized {
+    /// Creates a SQL `=` expression.
+    ///
+    /// ```ignore
+    /// users.filter(id.eq(1))
+    /// ```
+    fn eq<T: AsExpression<Self::SqlType>>(self, other: T) -> Eq<Self, T::Expression> {
+        Eq::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `!=` expression.
+    fn ne<T: AsExpression<Self::SqlType>>(self, other: T) -> NotEq<Self, T::Expression> {
+        NotEq::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `>` expression.
+    fn gt<T: AsExpression<Self::SqlType>>(self, other: T) -> Gt<Self, T::Expression> {
+        Gt::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `>=` expression.
+    fn ge<T: AsExpression<Self::SqlType>>(self, other: T) -> GtEq<Self, T::Expression> {
+        GtEq::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `<` expression.
+    fn lt<T: AsExpression<Self::SqlType>>(self, other: T) -> Lt<Self, T::Expression> {
+        Lt::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `<=` expression.
+    fn le<T: AsExpression<Self::SqlType>>(self, other: T) -> LtEq<Self, T::Expression> {
+        LtEq::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `BETWEEN` expression using the given lower and upper
+    /// bounds.
+    ///
+    /// ```ignore
+    /// users.filter(id.between(1, 5))
+    /// ```
+    fn between<T, U>(self, lower: T, upper: U) -> Between<Self, T::Expression, U::Expression>
+        where T: AsExpression<Self::SqlType>,
+              U: AsExpression<Self::SqlType>,
+    {
+        Between::new(self, lower.as_expression(), upper.as_expression())
+    }
+
+    /// Creates a SQL `IN (...)` expression, checking the expression against
+    /// every value yielded by `values`.
+    ///
+    /// ```ignore
+    /// users.filter(id.eq_any(vec![1, 2, 3]))
+    /// ```
+    fn eq_any<T, I>(self, values: I) -> In<Self, T::Expression>
+        where T: AsExpression<Self::SqlType>,
+              I: IntoIterator<Item = T>,
+    {
+        In::new(self, values.into_iter().map(AsExpression::as_expression).collect())
+    }
+}
+
+impl<T: ::expression::Expression> ExpressionMethods for T {}
+
+/// Methods present on text expressions.
+///
+/// This trait is exposed as `diesel::expression_methods::*`.
+pub trait TextExpressionMethods: ::expression::Expression<SqlType = VarChar> + Sized {
+    /// Creates a SQL `LIKE` expression.
+    ///
+    /// ```ignore
+    /// users.filter(name.like("%Sean%"))
+    /// ```
+    fn like<T: AsExpression<VarChar>>(self, other: T) -> Like<Self, T::Expression> {
+        Like::new(self, other.as_expression())
+    }
+}
+
+impl<T: ::expression::Expression<SqlType = VarChar>> TextExpressionMethods for T {}
@@ -0,0 +1,192 @@
+use backend::Backend;
+use expression::{AsExpression, Expression, NonAggregate, SelectableExpression};
+use query_builder::{BuildQueryResult, QueryBuilder, QueryFragment};
+use types::Bool;
+
+macro_rules! infix_predicate {
+    ($name:ident, $operator:expr) => {
+        infix_predicate!($name, $operator, backend: Backend);
+    };
+
+    ($name:ident, $operator:expr, backend: $backend:ty) => {
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $name<T, U> {
+            left: T,
+            right: U,
+        }
+
+        impl<T, U> $name<T, U> {
+            pub fn new(left: T, right: U) -> Self {
+                $name {
+                    left: left,
+                    right: right,
+                }
+            }
+        }
+
+        impl<T, U> Expression for $name<T, U> where
+            T: Expression,
+            U: Expression,
+        {
+            type SqlType = Bool;
+        }
+
+        impl<T, U, QS> SelectableExpression<QS> for $name<T, U> where
+            T: SelectableExpression<QS>,
+            U: SelectableExpression<QS>,
+            $name<T, U>: Expression,
+        {
+        }
+
+        impl<T, U> NonAggregate for $name<T, U> where
+            T: NonAggregate,
+            U: NonAggregate,
+            $name<T, U>: Expression,
+        {
+        }
+
+        impl<T, U, DB> QueryFragment<DB> for $name<T, U> where
+            DB: $backend,
+            T: QueryFragment<DB>,
+            U: QueryFragment<DB>,
+        {
+            fn to_sql(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult {
+                try!(self.left.to_sql(out));
+                out.push_sql($operator);
+                self.right.to_sql(out)
+            }
+        }
+    }
+}
+
+infix_predicate!(Eq, " = ");
+infix_predicate!(NotEq, " != ");
+infix_predicate!(Gt, " > ");
+infix_predicate!(GtEq, " >= ");
+infix_predicate!(Lt, " < ");
+infix_predicate!(LtEq, " <= ");
+infix_predicate!(Like, " LIKE ");
+
+/// The return type of [`expr.between(lower, upper)`](../expression_methods/trait.ExpressionMethods.html#method.between).
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Between<T, U, V> {
+    expr: T,
+    lower: U,
+    upper: V,
+}
+
+impl<T, U, V> Between<T, U, V> {
+    pub fn new(expr: T, lower: U, upper: V) -> Self {
+        Between {
+            expr: expr,
+            lower: lower,
+            upper: upper,
+        }
+    }
+}
+
+impl<T, U, V> Expression for Between<T, U, V> where
+    T: Expression,
+    U: Expression,
+    V: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<T, U, V, QS> SelectableExpression<QS> for Between<T, U, V> where
+    T: SelectableExpression<QS>,
+    U: SelectableExpression<QS>,
+    V: SelectableExpression<QS>,
+    Between<T, U, V>: Expression,
+{
+}
+
+impl<T, U, V> NonAggregate for Between<T, U, V> where
+    T: NonAggregate,
+    U: NonAggregate,
+    V: NonAggregate,
+    Between<T, U, V>: Expression,
+{
+}
+
+impl<T, U, V, DB> QueryFragment<DB> for Between<T, U, V> where
+    DB: Backend,
+    T: QueryFragment<DB>,
+    U: QueryFragment<DB>,
+    V: QueryFragment<DB>,
+{
+    fn to_sql(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult {
+        try!(self.expr.to_sql(out));
+        out.push_sql(" BETWEEN ");
+        try!(self.lower.to_sql(out));
+        out.push_sql(" AND ");
+        self.upper.to_sql(out)
+    }
+}
+
+/// The return type of [`expr.eq_any(values)`](../expression_methods/trait.ExpressionMethods.html#method.eq_any).
+/// Lowers to a SQL `IN (...)`.
+#[derive(Debug, Clone)]
+#[doc(hidden)]
+pub struct In<T, U> {
+    left: T,
+    values: Vec<U>,
+}
+
+impl<T, U> In<T, U> {
+    pub fn new(left: T, values: Vec<U>) -> Self {
+        In {
+            left: left,
+            values: values,
+        }
+    }
+}
+
+impl<T, U> Expression for In<T, U> where
+    T: Expression,
+    U: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<T, U, QS> SelectableExpression<QS> for In<T, U> where
+    T: SelectableExpression<QS>,
+    U: SelectableExpression<QS>,
+    In<T, U>: Expression,
+{
+}
+
+impl<T, U> NonAggregate for In<T, U> where
+    T: NonAggregate,
+    U: NonAggregate,
+    In<T, U>: Expression,
+{
+}
+
+impl<T, U, DB> QueryFragment<DB> for In<T, U> where
+    DB: Backend,
+    T: QueryFragment<DB>,
+    U: QueryFragment<DB>,
+{
+    fn to_sql(&self, out: &mut DB::QueryBuilder) -> BuildQueryResult {
+        if self.values.is_empty() {
+            // `col IN ()` is not valid SQL. An empty list of candidates can
+            // never match, so emit a predicate that's always false instead.
+            out.push_sql("1=0");
+            return Ok(());
+        }
+
+        try!(self.left.to_sql(out));
+        out.push_sql(" IN (");
+        for (i, value) in self.values.iter().enumerate() {
+            if i != 0 {
+                out.push_sql(", ");
+            }
+            try!(value.to_sql(out));
+        }
+        out.push_sql(")");
+        Ok(())
+    }
+}
@@ -2,10 +2,22 @@
 /// given struct. This macro should be called by copy/pasting the definition of
 /// the struct into it.
 ///
-/// The struct must have a field called `id`, and the type of that field must be
-/// `Copy`. This macro does not work with tuple structs.
+/// Unless a `#[primary_key(...)]` attribute (see below) names other columns,
+/// the struct must have a field called `id`. The type of that field is not
+/// restricted to integers or strings -- it can be anything that's a valid
+/// column type, including [`Uuid`][uuid], which makes it possible to use
+/// client-generated primary keys that don't require a round trip to the
+/// database to learn the row's identity. This macro does not work with tuple
+/// structs.
+///
+/// Tables whose key spans more than one column can opt in with a
+/// `#[primary_key(...)]` attribute, listed directly after `#[table_name]`.
+/// When given more than one column, `Id` becomes a tuple of references to
+/// each column's field, in the order they were declared in the attribute.
+/// When omitted, `#[primary_key(id)]` is assumed.
 ///
 /// [identifiable]: /diesel/associations/trait.Identifiable.html
+/// [uuid]: /diesel/types/struct.Uuid.html
 ///
 /// # Example
 ///
@@ -26,21 +38,55 @@
 /// }
 /// # fn main() {}
 /// ```
+///
+/// A table with a composite primary key:
+///
+/// ```no_run
+/// # #[macro_use] extern crate diesel;
+/// # table! { post_tags (post_id, tag_id) { post_id -> Integer, tag_id -> Integer, } }
+/// struct PostTag {
+///     post_id: i32,
+///     tag_id: i32,
+/// }
+///
+/// impl_Identifiable! {
+///     #[table_name(post_tags)]
+///     #[primary_key(post_id, tag_id)]
+///     struct PostTag {
+///         post_id: i32,
+///         tag_id: i32,
+///     }
+/// }
+/// # fn main() {}
+/// ```
 #[macro_export]
 macro_rules! impl_Identifiable {
-    // Extract table name from meta item
+    // Extract table name and an explicit primary key from meta items
+    (
+        $(())*
+        #[table_name($table_name:ident)]
+        #[primary_key($($pk:ident),+)]
+        $($rest:tt)*
+    ) => {
+        impl_Identifiable! {
+            (table_name = $table_name, primary_key = ($($pk),+),)
+            $($rest)*
+        }
+    };
+
+    // Extract table name from meta item, defaulting the primary key to `id`
     (
         $(())*
         #[table_name($table_name:ident)]
         $($rest:tt)*
     ) => {
         impl_Identifiable! {
-            (table_name = $table_name,)
+            (table_name = $table_name, primary_key = (id),)
             $($rest)*
         }
     };
 
-    // Strip meta items that aren't table name
+    // Strip meta items that aren't table name or primary key
     (
         $args:tt
         #[$ignore:meta]
@@ -58,20 +104,16 @@ macro_rules! impl_Identifiable {
         impl_Identifiable!($args $($body)*);
     };
 
-    // We found the `id` field, return the final impl
+    // Resolve the `id` field of the default, single-column primary key.
     (
         (
             table_name = $table_name:ident,
+            primary_key = (id),
             struct_ty = $struct_ty:ty,
+            struct_name = $struct_name:ident,
             lifetimes = ($($lifetimes:tt),*),
         ),
-        fields = [{
-            field_name: id,
-            column_name: $column_name:ident,
-            field_ty: $field_ty:ty,
-            field_kind: $field_kind:ident,
-            $($rest:tt)*
-        } $($fields:tt)*],
+        fields = $fields:tt,
     ) => {
         impl<$($lifetimes),*> $crate::associations::HasTable for $struct_ty {
             type Table = $table_name::table;
@@ -81,29 +123,52 @@ macro_rules! impl_Identifiable {
             }
         }
 
-        impl<'ident $(,$lifetimes)*> $crate::associations::Identifiable for &'ident $struct_ty {
-            type Id = &'ident $field_ty;
-
-            fn id(self) -> Self::Id {
-                &self.id
-            }
+        __diesel_identifiable_find_id! {
+            table_name = $table_name,
+            struct_ty = $struct_ty,
+            lifetimes = ($($lifetimes),*),
+            fields = $fields,
+            seen = [],
         }
     };
 
-    // Search for the `id` field and continue
+    // An explicit `#[primary_key(...)]` was given (one column or several).
+    // `macro_rules!` has no way to compare an attribute-supplied identifier
+    // against a struct field's name, so instead of searching for the pk
+    // column(s), we record the primary key fields' types in a per-struct module and
+    // reference the primary key column(s) directly -- the same trick
+    // `table!` uses so columns can be referenced by name.
     (
-        $args:tt,
-        fields = [{
-            field_name: $field_name:ident,
-            column_name: $column_name:ident,
-            field_ty: $field_ty:ty,
-            field_kind: $field_kind:ident,
-            $($rest:tt)*
-        } $($fields:tt)*],
+        (
+            table_name = $table_name:ident,
+            primary_key = ($($pk:ident),+),
+            struct_ty = $struct_ty:ty,
+            struct_name = $struct_name:ident,
+            lifetimes = ($($lifetimes:tt),*),
+        ),
+        fields = $fields:tt,
     ) => {
-        impl_Identifiable! {
-            $args,
-            fields = [$($fields)*],
+        impl<$($lifetimes),*> $crate::associations::HasTable for $struct_ty {
+            type Table = $table_name::table;
+
+            fn table() -> Self::Table {
+                $table_name::table
+            }
+        }
+
+        __diesel_identifiable_field_types! {
+            struct_name = $struct_name,
+            lifetimes = ($($lifetimes),*),
+            pk = ($($pk),+),
+            fields = $fields,
+            accum = [],
+        }
+
+        __diesel_identifiable_pk_impl! {
+            struct_ty = $struct_ty,
+            struct_name = $struct_name,
+            lifetimes = ($($lifetimes),*),
+            pk = ($($pk),+),
         }
     };
 
@@ -117,6 +182,7 @@ macro_rules! impl_Identifiable {
             (
                 $($args)*
                 struct_ty = $struct_name<$($lifetimes),*>,
+                struct_name = $struct_name,
                 lifetimes = ($($lifetimes),*),
             ),
             callback = impl_Identifiable,
@@ -134,6 +200,7 @@ macro_rules! impl_Identifiable {
             (
                 $($args)*
                 struct_ty = $struct_name,
+                struct_name = $struct_name,
                 lifetimes = (),
             ),
             callback = impl_Identifiable,
@@ -142,6 +209,189 @@ macro_rules! impl_Identifiable {
     };
 }
 
+// Joins a list of field names into a plain `name, name` string for use in
+// diagnostics. `stringify!($($seen),*)` isn't good enough for this, since
+// `stringify!` puts a space on both sides of every token it's given,
+// including the separating commas, rendering the list as `name , name`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __diesel_identifiable_join_field_names {
+    () => { "" };
+    ($first:ident) => { stringify!($first) };
+    ($first:ident, $($rest:ident),+) => {
+        concat!(stringify!($first), ", ", __diesel_identifiable_join_field_names!($($rest),+))
+    };
+}
+
+// Searches the fields of a struct for its default `id` primary key,
+// accumulating the names of the fields it's already ruled out along the way.
+// If the list is exhausted without finding one, the `fields = []` arm fires
+// instead of falling off the end of the macro, turning what would otherwise
+// be an inscrutable "no rules expected this token" error into a message that
+// names the missing field and lists what was actually found.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __diesel_identifiable_find_id {
+    // Found it
+    (
+        table_name = $table_name:ident,
+        struct_ty = $struct_ty:ty,
+        lifetimes = ($($lifetimes:tt),*),
+        fields = [{
+            field_name: id,
+            column_name: $column_name:ident,
+            field_ty: $field_ty:ty,
+            field_kind: $field_kind:ident,
+            $($rest:tt)*
+        } $($fields:tt)*],
+        seen = [$($seen:ident),*],
+    ) => {
+        impl<'ident $(,$lifetimes)*> $crate::associations::Identifiable for &'ident $struct_ty {
+            type Id = &'ident $field_ty;
+
+            fn id(self) -> Self::Id {
+                &self.id
+            }
+        }
+    };
+
+    // Not this one -- keep looking
+    (
+        table_name = $table_name:ident,
+        struct_ty = $struct_ty:ty,
+        lifetimes = ($($lifetimes:tt),*),
+        fields = [{
+            field_name: $field_name:ident,
+            column_name: $column_name:ident,
+            field_ty: $field_ty:ty,
+            field_kind: $field_kind:ident,
+            $($rest:tt)*
+        } $($fields:tt)*],
+        seen = [$($seen:ident),*],
+    ) => {
+        __diesel_identifiable_find_id! {
+            table_name = $table_name,
+            struct_ty = $struct_ty,
+            lifetimes = ($($lifetimes),*),
+            fields = [$($fields)*],
+            seen = [$($seen,)* $field_name],
+        }
+    };
+
+    // No field named `id` anywhere in the struct
+    (
+        table_name = $table_name:ident,
+        struct_ty = $struct_ty:ty,
+        lifetimes = ($($lifetimes:tt),*),
+        fields = [],
+        seen = [$($seen:ident),*],
+    ) => {
+        compile_error!(concat!(
+            "no field `id` for table `", stringify!($table_name), "`; ",
+            "available fields: ", __diesel_identifiable_join_field_names!($($seen),*),
+        ));
+    };
+}
+
+// Walks every field of a struct with an explicit `#[primary_key(...)]`,
+// recording the Rust type of just the primary key column(s) in a module
+// named after the struct, so that `__diesel_identifiable_pk_impl!` can refer
+// to a primary key column's type by path instead of needing to search for
+// it. `macro_rules!` has no way to compare a field's name against the names
+// listed in `#[primary_key(...)]`, so filtering down to just those columns
+// goes through a tiny dispatch macro generated with one literal arm per
+// primary key name -- the same trick `__diesel_identifiable_find_id!` uses
+// for the single hardcoded name `id`, just built at expansion time instead
+// of written out by hand. Every recorded alias is generic over the struct's
+// own lifetimes (there may be none), so a primary key field such as
+// `id: &'a str` can still be named from outside the macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __diesel_identifiable_field_types {
+    (
+        struct_name = $struct_name:ident,
+        lifetimes = ($($lifetimes:tt),*),
+        pk = ($($pk:ident),+),
+        fields = [],
+        accum = [$($body:tt)*],
+    ) => {
+        #[allow(non_snake_case, dead_code, unused_imports)]
+        mod $struct_name {
+            macro_rules! __is_pk_field {
+                $(
+                    ($pk, $ty:ty) => { pub type $pk<$($lifetimes),*> = $ty; };
+                )+
+                ($other:ident, $other_ty:ty) => {};
+            }
+
+            $($body)*
+        }
+    };
+
+    (
+        struct_name = $struct_name:ident,
+        lifetimes = $lifetimes:tt,
+        pk = $pk:tt,
+        fields = [{
+            field_name: $field_name:ident,
+            column_name: $column_name:ident,
+            field_ty: $field_ty:ty,
+            field_kind: $field_kind:ident,
+            $($rest:tt)*
+        } $($fields:tt)*],
+        accum = [$($body:tt)*],
+    ) => {
+        __diesel_identifiable_field_types! {
+            struct_name = $struct_name,
+            lifetimes = $lifetimes,
+            pk = $pk,
+            fields = [$($fields)*],
+            accum = [$($body)* __is_pk_field!($field_name, $field_ty);],
+        }
+    };
+}
+
+// Emits the `Identifiable` impl for an explicit `#[primary_key(...)]`, once
+// `__diesel_identifiable_field_types!` has recorded the primary key
+// column(s)' types.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __diesel_identifiable_pk_impl {
+    // A single, custom-named column. `Id` is a bare reference, just like the
+    // default `id` case.
+    (
+        struct_ty = $struct_ty:ty,
+        struct_name = $struct_name:ident,
+        lifetimes = ($($lifetimes:tt),*),
+        pk = ($pk:ident),
+    ) => {
+        impl<'ident $(,$lifetimes)*> $crate::associations::Identifiable for &'ident $struct_ty {
+            type Id = &'ident $struct_name::$pk<$($lifetimes),*>;
+
+            fn id(self) -> Self::Id {
+                &self.$pk
+            }
+        }
+    };
+
+    // A composite primary key. `Id` is a tuple of references, in the order
+    // the columns were declared in `#[primary_key(...)]`.
+    (
+        struct_ty = $struct_ty:ty,
+        struct_name = $struct_name:ident,
+        lifetimes = ($($lifetimes:tt),*),
+        pk = ($($pk:ident),+),
+    ) => {
+        impl<'ident $(,$lifetimes)*> $crate::associations::Identifiable for &'ident $struct_ty {
+            type Id = ($(&'ident $struct_name::$pk<$($lifetimes),*>),+);
+
+            fn id(self) -> Self::Id {
+                ($(&self.$pk),+)
+            }
+        }
+    };
+}
+
 table! {
     foos {
         id -> Integer,
@@ -154,6 +404,25 @@ table! {
     }
 }
 
+table! {
+    uuid_pks {
+        id -> Uuid,
+    }
+}
+
+table! {
+    post_tags (post_id, tag_id) {
+        post_id -> Integer,
+        tag_id -> Integer,
+    }
+}
+
+table! {
+    named_bars (custom_id) {
+        custom_id -> VarChar,
+    }
+}
+
 #[test]
 fn derive_identifiable_on_simple_struct() {
     use associations::Identifiable;
@@ -229,6 +498,78 @@ fn derive_identifiable_on_struct_with_non_integer_pk() {
     assert_eq!(&"there", foo2.id());
 }
 
+#[test]
+fn derive_identifiable_on_struct_with_uuid_pk() {
+    use associations::Identifiable;
+    use types::impls::uuid::UuidValue;
+
+    #[allow(missing_debug_implementations, missing_copy_implementations)]
+    struct Foo {
+        id: UuidValue,
+        #[allow(dead_code)]
+        foo: i32,
+    }
+
+    impl_Identifiable! {
+        #[table_name(uuid_pks)]
+        struct Foo {
+            id: UuidValue,
+            foo: i32,
+        }
+    }
+
+    let uuid = UuidValue::new_v4();
+    let foo = Foo { id: uuid, foo: 2 };
+    assert_eq!(&uuid, foo.id());
+}
+
+#[test]
+fn derive_identifiable_on_struct_with_composite_pk() {
+    use associations::Identifiable;
+
+    #[allow(missing_debug_implementations, missing_copy_implementations)]
+    struct PostTag {
+        post_id: i32,
+        tag_id: i32,
+    }
+
+    impl_Identifiable! {
+        #[table_name(post_tags)]
+        #[primary_key(post_id, tag_id)]
+        struct PostTag {
+            post_id: i32,
+            tag_id: i32,
+        }
+    }
+
+    let post_tag = PostTag { post_id: 1, tag_id: 2 };
+    assert_eq!((&1, &2), post_tag.id());
+}
+
+#[test]
+fn derive_identifiable_on_struct_with_explicit_single_column_pk() {
+    use associations::Identifiable;
+
+    #[allow(missing_debug_implementations, missing_copy_implementations)]
+    struct Foo {
+        #[allow(dead_code)]
+        foo: i32,
+        user_id: i32,
+    }
+
+    impl_Identifiable! {
+        #[table_name(foos)]
+        #[primary_key(user_id)]
+        struct Foo {
+            foo: i32,
+            user_id: i32,
+        }
+    }
+
+    let foo = Foo { foo: 2, user_id: 1 };
+    assert_eq!(&1, foo.id());
+}
+
 #[test]
 fn derive_identifiable_on_struct_with_lifetime() {
     use associations::Identifiable;
@@ -253,3 +594,27 @@ fn derive_identifiable_on_struct_with_lifetime() {
     assert_eq!(&"hi", foo1.id());
     assert_eq!(&"there", foo2.id());
 }
+
+#[test]
+fn derive_identifiable_on_struct_with_lifetime_and_explicit_pk() {
+    use associations::Identifiable;
+
+    #[allow(missing_debug_implementations, missing_copy_implementations)]
+    struct Foo<'a> {
+        #[allow(dead_code)]
+        foo: i32,
+        custom_id: &'a str,
+    }
+
+    impl_Identifiable! {
+        #[table_name(named_bars)]
+        #[primary_key(custom_id)]
+        struct Foo<'a> {
+            foo: i32,
+            custom_id: &'a str,
+        }
+    }
+
+    let foo = Foo { foo: 2, custom_id: "hi" };
+    assert_eq!(&"hi", foo.id());
+}
@@ -0,0 +1,3 @@
+mod primitives;
+mod text;
+pub mod uuid;
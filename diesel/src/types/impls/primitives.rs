@@ -0,0 +1,33 @@
+use expression::AsExpression;
+use expression::bound::Bound;
+use expression::helper_types::AsExprOf;
+use types;
+
+/// Implements `AsExpression` for an integer SQL type and the Rust integer
+/// type that backs it, so that integer literals of that width can be passed
+/// directly to `.eq()` and friends without an explicit cast. `ToSql`,
+/// `FromSql`, and `NativeSqlType` for these types are already provided by
+/// diesel's core impls for the primitive integer types.
+macro_rules! primitive_int_impls {
+    ($sql_type:ident, $rust_type:ty) => {
+        impl AsExpression<types::$sql_type> for $rust_type {
+            type Expression = AsExprOf<$rust_type, types::$sql_type>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+
+        impl<'a> AsExpression<types::$sql_type> for &'a $rust_type {
+            type Expression = AsExprOf<&'a $rust_type, types::$sql_type>;
+
+            fn as_expression(self) -> Self::Expression {
+                Bound::new(self)
+            }
+        }
+    }
+}
+
+primitive_int_impls!(SmallInt, i16);
+primitive_int_impls!(Integer, i32);
+primitive_int_impls!(BigInt, i64);
@@ -0,0 +1,24 @@
+use expression::AsExpression;
+use expression::bound::Bound;
+use expression::helper_types::AsExprOf;
+use types::VarChar;
+
+/// Allows an owned or borrowed `String` to be used anywhere a `&str` is
+/// accepted, so `.eq(some_string)` and `.eq(&some_string)` coerce to a
+/// `VarChar` column the same way a string literal does. `ToSql`/`FromSql`
+/// for `String` are already provided by diesel's core impl.
+impl AsExpression<VarChar> for String {
+    type Expression = AsExprOf<String, VarChar>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<VarChar> for &'a String {
+    type Expression = AsExprOf<&'a str, VarChar>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self.as_str())
+    }
+}
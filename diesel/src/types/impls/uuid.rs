@@ -0,0 +1,81 @@
+extern crate uuid;
+
+use std::error::Error;
+use std::io::Write;
+
+use backend::{Backend, Pg, Sqlite};
+use expression::AsExpression;
+use expression::bound::Bound;
+use expression::helper_types::AsExprOf;
+use types::{self, FromSql, IsNull, ToSql};
+
+pub use self::uuid::Uuid as UuidValue;
+
+/// The SQL `UUID` type.
+///
+/// ### [`ToSql`](/diesel/types/trait.ToSql.html) impl
+///
+/// - [`uuid::Uuid`][Uuid]
+///
+/// ### [`FromSql`](/diesel/types/trait.FromSql.html) impl
+///
+/// - [`uuid::Uuid`][Uuid]
+///
+/// On a backend with a native binary column (e.g. `PostgreSQL`'s `bytea`/
+/// `uuid`), the value is stored as its 16 raw bytes. On backends without one
+/// (e.g. SQLite), it round-trips through the canonical hyphenated string
+/// representation instead.
+///
+/// [Uuid]: https://docs.rs/uuid/*/uuid/struct.Uuid.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Uuid;
+
+impl types::NativeSqlType for Uuid {}
+
+impl ToSql<Uuid, Pg> for UuidValue {
+    fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error + Send + Sync>> {
+        out.write_all(self.as_bytes())
+            .map(|_| IsNull::No)
+            .map_err(|e| Box::new(e) as Box<Error + Send + Sync>)
+    }
+}
+
+impl FromSql<Uuid, Pg> for UuidValue {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error + Send + Sync>> {
+        let bytes = not_none!(bytes);
+        UuidValue::from_slice(bytes).map_err(|e| Box::new(e) as Box<Error + Send + Sync>)
+    }
+}
+
+impl ToSql<Uuid, Sqlite> for UuidValue {
+    fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error + Send + Sync>> {
+        let text = self.hyphenated().to_string();
+        out.write_all(text.as_bytes())
+            .map(|_| IsNull::No)
+            .map_err(|e| Box::new(e) as Box<Error + Send + Sync>)
+    }
+}
+
+impl FromSql<Uuid, Sqlite> for UuidValue {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error + Send + Sync>> {
+        let bytes = not_none!(bytes);
+        let text = try!(::std::str::from_utf8(bytes));
+        UuidValue::parse_str(text).map_err(|e| Box::new(e) as Box<Error + Send + Sync>)
+    }
+}
+
+impl AsExpression<Uuid> for UuidValue {
+    type Expression = AsExprOf<UuidValue, Uuid>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a> AsExpression<Uuid> for &'a UuidValue {
+    type Expression = AsExprOf<&'a UuidValue, Uuid>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
@@ -49,6 +49,85 @@ fn filter_by_equality_on_nullable_columns() {
     assert_eq!(vec![tess], connection.query_all(source).unwrap().collect::<Vec<_>>());
 }
 
+#[test]
+fn filter_by_ordered_comparisons() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let sean = User::new(1, "Sean");
+    let tess = User::new(2, "Tess");
+    assert_eq!(vec![tess.clone()], connection.query_all(users.filter(id.gt(1))).unwrap()
+        .collect::<Vec<_>>());
+    assert_eq!(vec![sean.clone(), tess.clone()], connection.query_all(users.filter(id.ge(1))).unwrap()
+        .collect::<Vec<_>>());
+    assert_eq!(vec![sean.clone()], connection.query_all(users.filter(id.lt(2))).unwrap()
+        .collect::<Vec<_>>());
+    assert_eq!(vec![sean.clone(), tess.clone()], connection.query_all(users.filter(id.le(2))).unwrap()
+        .collect::<Vec<_>>());
+    assert_eq!(vec![tess], connection.query_all(users.filter(id.ne(1))).unwrap()
+        .collect::<Vec<_>>());
+}
+
+#[test]
+fn filter_by_between() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    setup_users_table(&connection);
+    let data = [NewUser::new("Sean", None), NewUser::new("Tess", None), NewUser::new("Jim", None)];
+    connection.insert_without_return(&users, &data).unwrap();
+
+    let sean = User::new(1, "Sean");
+    let tess = User::new(2, "Tess");
+    let source = users.filter(id.between(1, 2));
+    assert_eq!(vec![sean, tess], connection.query_all(source).unwrap().collect::<Vec<_>>());
+}
+
+#[test]
+fn filter_by_like() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let sean = User::new(1, "Sean");
+    let source = users.filter(name.like("%ea%"));
+    assert_eq!(vec![sean], connection.query_all(source).unwrap().collect::<Vec<_>>());
+}
+
+#[test]
+fn filter_by_eq_any() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    setup_users_table(&connection);
+    let data = [NewUser::new("Sean", None), NewUser::new("Tess", None), NewUser::new("Jim", None)];
+    connection.insert_without_return(&users, &data).unwrap();
+
+    let sean = User::new(1, "Sean");
+    let jim = User::new(3, "Jim");
+    let source = users.filter(name.eq_any(vec!["Sean", "Jim"]));
+    assert_eq!(vec![sean, jim], connection.query_all(source).unwrap().collect::<Vec<_>>());
+}
+
+#[test]
+fn filter_by_comparison_composed_with_and() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    setup_users_table(&connection);
+    let data = [
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    connection.insert_without_return(&users, &data).unwrap();
+
+    let jim = User::with_hair_color(3, "Jim", "black");
+    let source = users.filter(id.gt(1).and(hair_color.eq("black")));
+    assert_eq!(vec![jim], connection.query_all(source).unwrap().collect::<Vec<_>>());
+}
+
 #[test]
 fn filter_after_joining() {
     use schema::users::name;